@@ -0,0 +1,31 @@
+use crate::node::{ParentNode, RTreeNode};
+use crate::object::RTreeObject;
+use crate::params::{InsertionStrategy, RTreeParams};
+
+/// Bulk-loads a tree's root from `elements`, recursively partitioning with `Params::Strategy` —
+/// the same strategy used to split an overflowing node during incremental insertion, so a
+/// bulk-loaded tree and an incrementally-built one under the same `Params` are shaped by the
+/// same heuristic.
+pub fn bulk_load<T, Params>(elements: Vec<T>) -> ParentNode<T>
+where
+    T: RTreeObject,
+    Params: RTreeParams,
+{
+    let nodes: Vec<RTreeNode<T>> = elements.into_iter().map(RTreeNode::Leaf).collect();
+    partition::<T, Params>(nodes)
+}
+
+fn partition<T, Params>(mut nodes: Vec<RTreeNode<T>>) -> ParentNode<T>
+where
+    T: RTreeObject,
+    Params: RTreeParams,
+{
+    if nodes.len() <= Params::MAX_SIZE {
+        return ParentNode::new_from_children(nodes);
+    }
+
+    let right_nodes = Params::Strategy::split(Params::MIN_SIZE, &mut nodes);
+    let left = RTreeNode::Parent(partition::<T, Params>(nodes));
+    let right = RTreeNode::Parent(partition::<T, Params>(right_nodes));
+    ParentNode::new_from_children(vec![left, right])
+}
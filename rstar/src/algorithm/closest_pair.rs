@@ -0,0 +1,125 @@
+use crate::envelope::Envelope;
+use crate::node::{ParentNode, RTreeNode};
+use crate::object::RTreeObject;
+use crate::util::{MinScalar, Scalar};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+struct PairEntry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    dist: MinScalar<S>,
+    left: &'a RTreeNode<T>,
+    right: &'a RTreeNode<T>,
+}
+
+impl<'a, T, S: PartialEq> PartialEq for PairEntry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.dist == other.dist
+    }
+}
+impl<'a, T, S: PartialEq> Eq for PairEntry<'a, T, S> where T: RTreeObject {}
+impl<'a, T, S: PartialOrd> PartialOrd for PairEntry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'a, T, S: PartialOrd> Ord for PairEntry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist.cmp(&other.dist)
+    }
+}
+
+/// Finds the closest pair of elements between two r-trees' root nodes, or `None` if either
+/// tree is empty.
+///
+/// Performs a synchronized best-first traversal: pairs of nodes are explored in increasing
+/// order of [`Envelope::min_distance_2`] between them, using [`Envelope::max_distance_2`]
+/// between the two roots as an initial (loose) upper bound on the answer. Once a pair of
+/// leaves is reached, it becomes a candidate for the best answer; any pending pair of subtrees
+/// whose `min_distance_2` exceeds the best distance found so far is dropped instead of being
+/// expanded, so subtrees that cannot possibly contain a closer pair are never visited.
+pub fn closest_pair<'a, T>(left: &'a ParentNode<T>, right: &'a ParentNode<T>) -> Option<(&'a T, &'a T)>
+where
+    T: RTreeObject,
+{
+    if left.children.is_empty() || right.children.is_empty() {
+        return None;
+    }
+
+    let mut best_bound = left.envelope.max_distance_2(&right.envelope);
+    let mut best: Option<(&'a T, &'a T, Scalar<T>)> = None;
+    let mut heap = BinaryHeap::new();
+
+    for l in &left.children {
+        for r in &right.children {
+            push_pair(&mut heap, l, r, &best_bound);
+        }
+    }
+
+    while let Some(entry) = heap.pop() {
+        let worse_than_best = best
+            .as_ref()
+            .is_some_and(|(_, _, best_dist)| entry.dist.0 > *best_dist);
+        if worse_than_best {
+            break;
+        }
+        match (entry.left, entry.right) {
+            (RTreeNode::Leaf(l), RTreeNode::Leaf(r)) => {
+                let dist = l.envelope().min_distance_2(&r.envelope());
+                if best.as_ref().is_none_or(|(_, _, best_dist)| dist < *best_dist) {
+                    best_bound = dist;
+                    best = Some((l, r, dist));
+                }
+            }
+            (RTreeNode::Leaf(_), RTreeNode::Parent(rp)) => {
+                for rc in &rp.children {
+                    push_pair(&mut heap, entry.left, rc, &best_bound);
+                }
+            }
+            (RTreeNode::Parent(lp), RTreeNode::Leaf(_)) => {
+                for lc in &lp.children {
+                    push_pair(&mut heap, lc, entry.right, &best_bound);
+                }
+            }
+            (RTreeNode::Parent(lp), RTreeNode::Parent(rp)) => {
+                for lc in &lp.children {
+                    for rc in &rp.children {
+                        push_pair(&mut heap, lc, rc, &best_bound);
+                    }
+                }
+            }
+        }
+    }
+
+    best.map(|(l, r, _)| (l, r))
+}
+
+fn push_pair<'a, T>(
+    heap: &mut BinaryHeap<PairEntry<'a, T, Scalar<T>>>,
+    left: &'a RTreeNode<T>,
+    right: &'a RTreeNode<T>,
+    best_bound: &Scalar<T>,
+) where
+    T: RTreeObject,
+{
+    let dist = left.envelope().min_distance_2(&right.envelope());
+    if dist > *best_bound {
+        return;
+    }
+    heap.push(PairEntry {
+        dist: MinScalar(dist),
+        left,
+        right,
+    });
+}
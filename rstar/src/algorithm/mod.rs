@@ -0,0 +1,5 @@
+pub mod bulk_load;
+pub mod closest_pair;
+pub mod ray_query;
+pub mod rstar;
+pub mod sah;
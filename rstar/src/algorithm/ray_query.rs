@@ -0,0 +1,105 @@
+use crate::node::{ParentNode, RTreeNode};
+use crate::object::RTreeObject;
+use crate::point::Point;
+use crate::structures::aabb::{Ray, AABB};
+use crate::util::MinScalar;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+struct Entry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    t: MinScalar<S>,
+    node: &'a RTreeNode<T>,
+}
+
+impl<'a, T, S: PartialEq> PartialEq for Entry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t
+    }
+}
+impl<'a, T, S: PartialEq> Eq for Entry<'a, T, S> where T: RTreeObject {}
+impl<'a, T, S: PartialOrd> PartialOrd for Entry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<'a, T, S: PartialOrd> Ord for Entry<'a, T, S>
+where
+    T: RTreeObject,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.t.cmp(&other.t)
+    }
+}
+
+/// A lazy iterator over the elements of an r-tree whose envelope `ray` crosses, yielded in
+/// increasing order of the entry parameter `t` returned by [`AABB::intersects_ray`].
+///
+/// Descends the tree best-first using a binary heap keyed on each pushed node's own `t`. A
+/// child's `t` can never be smaller than its parent's (a sub-box cannot be entered before the
+/// box containing it), so popping the heap's minimum always yields elements in increasing `t`
+/// order; a subtree whose node AABB the ray misses is never pushed, pruning it immediately.
+pub struct RayIntersections<'a, T, P>
+where
+    T: RTreeObject<Envelope = AABB<P>>,
+    P: Point,
+{
+    ray: Ray<P>,
+    heap: BinaryHeap<Entry<'a, T, P::Scalar>>,
+}
+
+impl<'a, T, P> RayIntersections<'a, T, P>
+where
+    T: RTreeObject<Envelope = AABB<P>>,
+    P: Point,
+{
+    pub(crate) fn new(root: &'a ParentNode<T>, ray: Ray<P>) -> Self {
+        let mut result = RayIntersections {
+            ray,
+            heap: BinaryHeap::new(),
+        };
+        for child in &root.children {
+            result.push_if_hit(child);
+        }
+        result
+    }
+
+    fn push_if_hit(&mut self, node: &'a RTreeNode<T>) {
+        if let Some(t) = node.envelope().intersects_ray(&self.ray) {
+            self.heap.push(Entry {
+                t: MinScalar(t),
+                node,
+            });
+        }
+    }
+}
+
+impl<'a, T, P> Iterator for RayIntersections<'a, T, P>
+where
+    T: RTreeObject<Envelope = AABB<P>>,
+    P: Point,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Entry { node, .. } = self.heap.pop()?;
+            match node {
+                RTreeNode::Leaf(leaf) => return Some(leaf),
+                RTreeNode::Parent(parent) => {
+                    for child in &parent.children {
+                        self.push_if_hit(child);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::envelope::Envelope;
+use crate::node::RTreeNode;
+use crate::object::RTreeObject;
+use crate::params::InsertionStrategy;
+use crate::point::Point;
+use num_traits::Zero;
+
+/// The crate's original node-splitting strategy, based on the R*-tree paper: the split axis is
+/// the one minimizing the sum of margins over every valid split position, and the split
+/// position on that axis is the one minimizing overlap area (breaking ties by total area).
+pub struct RStarInsertionStrategy;
+
+fn merged_envelope<T>(nodes: &[RTreeNode<T>]) -> T::Envelope
+where
+    T: RTreeObject,
+{
+    let mut envelope = T::Envelope::new_empty();
+    for node in nodes {
+        envelope.merge(&node.envelope());
+    }
+    envelope
+}
+
+fn margin_sum<T>(min_size: usize, children: &[RTreeNode<T>]) -> <<T::Envelope as Envelope>::Point as Point>::Scalar
+where
+    T: RTreeObject,
+{
+    let len = children.len();
+    let mut sum = <<T::Envelope as Envelope>::Point as Point>::Scalar::zero();
+    for split in min_size..=(len - min_size) {
+        let left = merged_envelope::<T>(&children[..split]);
+        let right = merged_envelope::<T>(&children[split..]);
+        sum = sum + left.margin_value() + right.margin_value();
+    }
+    sum
+}
+
+impl InsertionStrategy for RStarInsertionStrategy {
+    fn split<T>(min_size: usize, children: &mut Vec<RTreeNode<T>>) -> Vec<RTreeNode<T>>
+    where
+        T: RTreeObject,
+    {
+        let len = children.len();
+        debug_assert!(len >= 2 * min_size);
+
+        let dimensions = <<T::Envelope as Envelope>::Point as Point>::DIMENSIONS;
+        let mut best_axis = 0;
+        let mut best_margin_sum = None;
+        for axis in 0..dimensions {
+            T::Envelope::sort_envelopes(axis, children, |c| c.envelope());
+            let sum = margin_sum::<T>(min_size, children);
+            if best_margin_sum.is_none_or(|best| sum < best) {
+                best_margin_sum = Some(sum);
+                best_axis = axis;
+            }
+        }
+        T::Envelope::sort_envelopes(best_axis, children, |c| c.envelope());
+
+        let mut best_split = min_size;
+        let mut best_overlap_area = None;
+        let mut best_total_area = None;
+        for split in min_size..=(len - min_size) {
+            let left = merged_envelope::<T>(&children[..split]);
+            let right = merged_envelope::<T>(&children[split..]);
+            let overlap_area = left.intersection_area(&right);
+            let total_area = left.area() + right.area();
+            let is_better = match best_overlap_area {
+                None => true,
+                Some(best_overlap) if overlap_area < best_overlap => true,
+                Some(best_overlap) if overlap_area == best_overlap => {
+                    best_total_area.is_none_or(|best_total| total_area < best_total)
+                }
+                _ => false,
+            };
+            if is_better {
+                best_overlap_area = Some(overlap_area);
+                best_total_area = Some(total_area);
+                best_split = split;
+            }
+        }
+
+        children.split_off(best_split)
+    }
+}
@@ -0,0 +1,171 @@
+use crate::envelope::Envelope;
+use crate::node::RTreeNode;
+use crate::object::RTreeObject;
+use crate::params::InsertionStrategy;
+use crate::point::Point;
+use crate::util::Scalar;
+use num_traits::{One, Zero};
+
+/// A node-splitting and bulk-loading strategy that minimizes the Surface Area Heuristic (SAH)
+/// cost instead of the R*-tree margin/overlap heuristic used by
+/// [`RStarInsertionStrategy`](crate::algorithm::rstar::RStarInsertionStrategy).
+///
+/// For a candidate split into a `left` group of `left_count` entries and a `right` group of
+/// `right_count` entries, the cost is `area(left) * left_count + area(right) * right_count`,
+/// where `area` is [`Envelope::area`] (this crate's n-dimensional content measure, not a
+/// literal surface area — using the sum of face areas instead would require a distinct measure
+/// function). Lower cost means less expected work scanning through the split during a range
+/// query, so minimizing it produces trees tuned for read-heavy, range-query-dominated
+/// workloads.
+pub struct SAHInsertionStrategy;
+
+/// Sorts `children` by the center of their envelope along `axis`.
+fn sort_by_center<T>(axis: usize, children: &mut [RTreeNode<T>])
+where
+    T: RTreeObject,
+{
+    children.sort_by(|a, b| {
+        let ca = a.envelope().center().nth(axis);
+        let cb = b.envelope().center().nth(axis);
+        ca.partial_cmp(&cb).unwrap()
+    });
+}
+
+/// Finds the split position minimizing the [SAH cost](SAHInsertionStrategy) of dividing
+/// `children` (already sorted along `axis`) into two groups, each holding at least `min_size`
+/// entries.
+///
+/// Sweeps the candidate split positions while maintaining the merged envelope of everything
+/// before and everything from the candidate onward, so both envelopes needed by every
+/// candidate are available from a single left-to-right and a single right-to-left pass. Each
+/// group's element count is tracked as a running `Scalar` total (`left_count` incremented,
+/// `right_count` derived as `total - left_count`) instead of being recomputed per candidate, so
+/// every candidate's cost is evaluated in O(1) and the whole sweep is O(n).
+fn best_split_position<T>(
+    min_size: usize,
+    children: &[RTreeNode<T>],
+) -> (usize, Scalar<T>)
+where
+    T: RTreeObject,
+{
+    let len = children.len();
+    let zero = Scalar::<T>::zero();
+    let one = Scalar::<T>::one();
+
+    let mut suffix_merged = Vec::with_capacity(len + 1);
+    suffix_merged.push(T::Envelope::new_empty());
+    for node in children.iter().rev() {
+        let merged = suffix_merged.last().unwrap().merged(&node.envelope());
+        suffix_merged.push(merged);
+    }
+    suffix_merged.reverse();
+
+    let mut total_count = zero;
+    for _ in 0..len {
+        total_count = total_count + one;
+    }
+
+    let mut prefix = T::Envelope::new_empty();
+    let mut left_count = zero;
+    let mut best_split = min_size;
+    let mut best_cost = None;
+    for split in 1..len {
+        prefix = prefix.merged(&children[split - 1].envelope());
+        left_count = left_count + one;
+        if split < min_size || len - split < min_size {
+            continue;
+        }
+        let right_count = total_count - left_count;
+        let cost = prefix.area() * left_count + suffix_merged[split].area() * right_count;
+        if best_cost.is_none_or(|best| cost < best) {
+            best_cost = Some(cost);
+            best_split = split;
+        }
+    }
+
+    (best_split, best_cost.unwrap_or(zero))
+}
+
+impl InsertionStrategy for SAHInsertionStrategy {
+    fn split<T>(min_size: usize, children: &mut Vec<RTreeNode<T>>) -> Vec<RTreeNode<T>>
+    where
+        T: RTreeObject,
+    {
+        let len = children.len();
+        debug_assert!(len >= 2 * min_size);
+
+        let dimensions = <<T::Envelope as Envelope>::Point as Point>::DIMENSIONS;
+        let mut best_axis = 0;
+        let mut best_split = min_size;
+        let mut best_cost = None;
+        for axis in 0..dimensions {
+            sort_by_center::<T>(axis, children);
+            let (split, cost) = best_split_position::<T>(min_size, children);
+            if best_cost.is_none_or(|best| cost < best) {
+                best_cost = Some(cost);
+                best_axis = axis;
+                best_split = split;
+            }
+        }
+        sort_by_center::<T>(best_axis, children);
+
+        children.split_off(best_split)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SAHInsertionStrategy;
+    use crate::node::RTreeNode;
+    use crate::params::InsertionStrategy;
+
+    fn leaves(points: &[[f64; 2]]) -> Vec<RTreeNode<[f64; 2]>> {
+        points.iter().copied().map(RTreeNode::Leaf).collect()
+    }
+
+    fn point_of(node: &RTreeNode<[f64; 2]>) -> [f64; 2] {
+        match node {
+            RTreeNode::Leaf(p) => *p,
+            RTreeNode::Parent(_) => panic!("expected a leaf"),
+        }
+    }
+
+    #[test]
+    fn splits_on_the_cheaper_axis() {
+        // Splitting by y separates the two rows into zero-height, zero-area strips, which is
+        // strictly cheaper than every valid x split (each of which mixes both rows into a
+        // 5-unit-tall box). The SAH split must pick the y axis even though two of the x
+        // candidates tie with each other (splitting after 2 or after 4 entries both cost 20;
+        // splitting after 3 costs 30).
+        let mut children = leaves(&[
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [0.0, 5.0],
+            [1.0, 5.0],
+            [2.0, 5.0],
+        ]);
+        let right = SAHInsertionStrategy::split(2, &mut children);
+
+        let left_ys: Vec<f64> = children.iter().map(|n| point_of(n)[1]).collect();
+        let right_ys: Vec<f64> = right.iter().map(|n| point_of(n)[1]).collect();
+        assert!(left_ys.iter().all(|&y| y == left_ys[0]));
+        assert!(right_ys.iter().all(|&y| y == right_ys[0]));
+        assert_ne!(left_ys[0], right_ys[0]);
+    }
+
+    #[test]
+    fn respects_min_size_on_both_sides() {
+        let mut children = leaves(&[
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [3.0, 0.0],
+            [4.0, 0.0],
+            [5.0, 0.0],
+        ]);
+        let right = SAHInsertionStrategy::split(3, &mut children);
+        assert_eq!(children.len(), 3);
+        assert_eq!(right.len(), 3);
+    }
+}
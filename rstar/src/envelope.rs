@@ -0,0 +1,59 @@
+use crate::point::Point;
+
+/// An envelope encapsulating a geometric object, used to accelerate spatial queries.
+///
+/// [`AABB`](crate::AABB) is currently the only implementation of this trait.
+pub trait Envelope: Clone {
+    /// The point type used by this envelope.
+    type Point: Point;
+
+    /// Creates a new, empty envelope.
+    fn new_empty() -> Self;
+
+    /// Returns `true` if this envelope contains `point`.
+    fn contains_point(&self, point: &Self::Point) -> bool;
+
+    /// Returns `true` if this envelope completely contains `other`.
+    fn contains_envelope(&self, other: &Self) -> bool;
+
+    /// Enlarges this envelope in place so that it also contains `other`.
+    fn merge(&mut self, other: &Self);
+
+    /// Returns the smallest envelope containing both `self` and `other`.
+    fn merged(&self, other: &Self) -> Self;
+
+    /// Returns `true` if `self` and `other` share at least one point.
+    fn intersects(&self, other: &Self) -> bool;
+
+    /// Returns this envelope's content measure (e.g. an n-dimensional volume).
+    fn area(&self) -> <Self::Point as Point>::Scalar;
+
+    /// Returns the squared distance from `point` to the closest point contained in this
+    /// envelope.
+    fn distance_2(&self, point: &Self::Point) -> <Self::Point as Point>::Scalar;
+
+    /// Returns an upper bound for the squared distance from `point` to the closest point of
+    /// any object contained in this envelope.
+    fn min_max_dist_2(&self, point: &Self::Point) -> <Self::Point as Point>::Scalar;
+
+    /// Returns the squared distance between the nearest points of `self` and `other`, or zero
+    /// if the two envelopes intersect.
+    fn min_distance_2(&self, other: &Self) -> <Self::Point as Point>::Scalar;
+
+    /// Returns the squared distance between the farthest points of `self` and `other`.
+    fn max_distance_2(&self, other: &Self) -> <Self::Point as Point>::Scalar;
+
+    /// Returns the envelope's center point.
+    fn center(&self) -> Self::Point;
+
+    /// Returns the content measure of the intersection of `self` and `other`.
+    fn intersection_area(&self, other: &Self) -> <Self::Point as Point>::Scalar;
+
+    /// Returns the sum of this envelope's side lengths.
+    fn margin_value(&self) -> <Self::Point as Point>::Scalar;
+
+    /// Sorts `envelopes` by the lower bound of `f(envelope)` along `axis`.
+    fn sort_envelopes<T, F>(axis: usize, envelopes: &mut [T], f: F)
+    where
+        F: Fn(&T) -> Self;
+}
@@ -0,0 +1,19 @@
+//! An n-dimensional r-tree for accelerating spatial queries.
+
+mod algorithm;
+mod envelope;
+mod node;
+mod object;
+mod params;
+mod point;
+mod rtree;
+mod structures;
+mod util;
+
+pub use crate::algorithm::ray_query::RayIntersections;
+pub use crate::envelope::Envelope;
+pub use crate::object::{PointDistance, RTreeObject};
+pub use crate::params::{DefaultParams, InsertionStrategy, RTreeParams, SAHParams};
+pub use crate::point::{Point, PointExt, RTreeNum};
+pub use crate::rtree::{Iter, RTree};
+pub use crate::structures::aabb::{Ray, AABB};
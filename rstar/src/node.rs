@@ -0,0 +1,55 @@
+use crate::envelope::Envelope;
+use crate::object::RTreeObject;
+
+/// A node of an [`RTree`](crate::RTree): either a stored object, or an inner node grouping
+/// other nodes under a shared envelope.
+pub enum RTreeNode<T>
+where
+    T: RTreeObject,
+{
+    Leaf(T),
+    Parent(ParentNode<T>),
+}
+
+impl<T> RTreeNode<T>
+where
+    T: RTreeObject,
+{
+    /// Returns the envelope of this node: the object's own envelope for a leaf, or the
+    /// precomputed merged envelope of its children for a parent.
+    pub fn envelope(&self) -> T::Envelope {
+        match self {
+            RTreeNode::Leaf(t) => t.envelope(),
+            RTreeNode::Parent(parent) => parent.envelope.clone(),
+        }
+    }
+}
+
+/// An inner node of an [`RTree`](crate::RTree), holding its children and their merged envelope.
+pub struct ParentNode<T>
+where
+    T: RTreeObject,
+{
+    pub envelope: T::Envelope,
+    pub children: Vec<RTreeNode<T>>,
+}
+
+impl<T> ParentNode<T>
+where
+    T: RTreeObject,
+{
+    pub fn new_empty() -> Self {
+        ParentNode {
+            envelope: T::Envelope::new_empty(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn new_from_children(children: Vec<RTreeNode<T>>) -> Self {
+        let mut envelope = T::Envelope::new_empty();
+        for child in &children {
+            envelope.merge(&child.envelope());
+        }
+        ParentNode { envelope, children }
+    }
+}
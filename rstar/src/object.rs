@@ -0,0 +1,35 @@
+use crate::envelope::Envelope;
+use crate::point::Point;
+use crate::structures::aabb::AABB;
+
+/// A type that can be stored within an r-tree.
+pub trait RTreeObject {
+    /// The envelope type enclosing this object; determines the tree's point type and
+    /// dimensionality.
+    type Envelope: Envelope;
+
+    /// Returns the smallest envelope containing this object.
+    fn envelope(&self) -> Self::Envelope;
+}
+
+impl<P> RTreeObject for P
+where
+    P: Point,
+{
+    type Envelope = AABB<P>;
+
+    fn envelope(&self) -> AABB<P> {
+        AABB::from_point(*self)
+    }
+}
+
+/// An [`RTreeObject`] that can compute its exact distance to a point.
+///
+/// None of this crate's tree queries call this trait; they work from envelopes alone.
+pub trait PointDistance: RTreeObject {
+    /// Returns the squared distance between this object and `point`.
+    fn distance_2(
+        &self,
+        point: &<Self::Envelope as Envelope>::Point,
+    ) -> <<Self::Envelope as Envelope>::Point as Point>::Scalar;
+}
@@ -0,0 +1,46 @@
+use crate::node::RTreeNode;
+use crate::object::RTreeObject;
+
+/// A strategy for partitioning an overflowing node's children into two groups, and for
+/// grouping elements into nodes during bulk loading.
+pub trait InsertionStrategy {
+    /// Splits `children` into two groups, each respecting `min_size`. The first group is left
+    /// in place in `children`; the second group is returned.
+    fn split<T>(min_size: usize, children: &mut Vec<RTreeNode<T>>) -> Vec<RTreeNode<T>>
+    where
+        T: RTreeObject;
+}
+
+/// Tuning parameters controlling an [`RTree`](crate::RTree)'s shape and splitting behavior.
+pub trait RTreeParams: Clone {
+    /// The minimum number of children of an inner node (except the root).
+    const MIN_SIZE: usize;
+    /// The maximum number of children of an inner node before it must be split.
+    const MAX_SIZE: usize;
+    /// The strategy used to split overflowing nodes and to group elements during bulk loading.
+    type Strategy: InsertionStrategy;
+}
+
+/// The crate's default tuning parameters, using the margin/overlap-minimizing
+/// [`RStarInsertionStrategy`](crate::algorithm::rstar::RStarInsertionStrategy).
+#[derive(Clone)]
+pub struct DefaultParams;
+
+impl RTreeParams for DefaultParams {
+    const MIN_SIZE: usize = 3;
+    const MAX_SIZE: usize = 6;
+    type Strategy = crate::algorithm::rstar::RStarInsertionStrategy;
+}
+
+/// Tuning parameters identical to [`DefaultParams`] except for splitting with the
+/// cost-minimizing [`SAHInsertionStrategy`](crate::algorithm::sah::SAHInsertionStrategy)
+/// instead of the margin/overlap-minimizing R*-tree heuristic. Prefer this for read-heavy,
+/// range-query-dominated workloads.
+#[derive(Clone)]
+pub struct SAHParams;
+
+impl RTreeParams for SAHParams {
+    const MIN_SIZE: usize = 3;
+    const MAX_SIZE: usize = 6;
+    type Strategy = crate::algorithm::sah::SAHInsertionStrategy;
+}
@@ -0,0 +1,164 @@
+use num_traits::{Bounded, Signed, Zero};
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A scalar type usable as a [`Point`]'s coordinate.
+///
+/// Implemented for every signed, bounded, ordered number type; no manual implementation should
+/// be required.
+pub trait RTreeNum:
+    Bounded
+    + Signed
+    + PartialOrd
+    + Copy
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+}
+
+impl<S> RTreeNum for S where
+    S: Bounded
+        + Signed
+        + PartialOrd
+        + Copy
+        + Debug
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+{
+}
+
+/// A point in n-dimensional space.
+///
+/// This is the central trait defining which point types can be stored within an r-tree. Using
+/// an n-dimensional point type will result in an n-dimensional [`AABB`](crate::AABB) and tree.
+pub trait Point: Copy + Clone + PartialEq + Debug {
+    /// The number type used by each of this point's components.
+    type Scalar: RTreeNum;
+
+    /// The number of dimensions of this point type.
+    const DIMENSIONS: usize;
+
+    /// Creates a new point with all components set to zero.
+    fn new() -> Self;
+
+    /// Creates a new point with all components set to `value`.
+    fn from_value(value: Self::Scalar) -> Self;
+
+    /// Returns the value of the `index`th component.
+    fn nth(&self, index: usize) -> Self::Scalar;
+
+    /// Returns a mutable reference to the `index`th component.
+    fn nth_mut(&mut self, index: usize) -> &mut Self::Scalar;
+}
+
+/// Extension methods derived from [`Point`]'s required methods.
+///
+/// Blanket-implemented for every [`Point`] so individual point types never need to repeat this
+/// boilerplate.
+pub trait PointExt: Point {
+    fn component_wise(
+        &self,
+        other: &Self,
+        f: impl Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    ) -> Self;
+    fn all_component_wise(&self, other: &Self, f: impl Fn(Self::Scalar, Self::Scalar) -> bool) -> bool;
+    fn fold<T>(&self, start_value: T, f: impl Fn(T, Self::Scalar) -> T) -> T;
+    fn min_point(&self, other: &Self) -> Self;
+    fn max_point(&self, other: &Self) -> Self;
+    fn sub(&self, other: &Self) -> Self;
+    fn add(&self, other: &Self) -> Self;
+    fn length_2(&self) -> Self::Scalar;
+}
+
+impl<P> PointExt for P
+where
+    P: Point,
+{
+    fn component_wise(
+        &self,
+        other: &Self,
+        f: impl Fn(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    ) -> Self {
+        let mut result = Self::new();
+        for i in 0..Self::DIMENSIONS {
+            *result.nth_mut(i) = f(self.nth(i), other.nth(i));
+        }
+        result
+    }
+
+    fn all_component_wise(&self, other: &Self, f: impl Fn(Self::Scalar, Self::Scalar) -> bool) -> bool {
+        (0..Self::DIMENSIONS).all(|i| f(self.nth(i), other.nth(i)))
+    }
+
+    fn fold<T>(&self, start_value: T, f: impl Fn(T, Self::Scalar) -> T) -> T {
+        let mut result = start_value;
+        for i in 0..Self::DIMENSIONS {
+            result = f(result, self.nth(i));
+        }
+        result
+    }
+
+    fn min_point(&self, other: &Self) -> Self {
+        self.component_wise(other, |a, b| if a < b { a } else { b })
+    }
+
+    fn max_point(&self, other: &Self) -> Self {
+        self.component_wise(other, |a, b| if a > b { a } else { b })
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.component_wise(other, |a, b| a - b)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.component_wise(other, |a, b| a + b)
+    }
+
+    fn length_2(&self) -> Self::Scalar {
+        self.fold(Self::Scalar::zero(), |acc, cur| acc + cur * cur)
+    }
+}
+
+/// Returns the larger of two scalars, as ordered by `PartialOrd`.
+pub fn max_inline<S: PartialOrd>(a: S, b: S) -> S {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+macro_rules! impl_point {
+    ($len:expr) => {
+        impl<S: RTreeNum> Point for [S; $len] {
+            type Scalar = S;
+            const DIMENSIONS: usize = $len;
+
+            fn new() -> Self {
+                [S::zero(); $len]
+            }
+
+            fn from_value(value: S) -> Self {
+                [value; $len]
+            }
+
+            fn nth(&self, index: usize) -> S {
+                self[index]
+            }
+
+            fn nth_mut(&mut self, index: usize) -> &mut S {
+                &mut self[index]
+            }
+        }
+    };
+}
+
+impl_point!(1);
+impl_point!(2);
+impl_point!(3);
+impl_point!(4);
@@ -0,0 +1,226 @@
+use crate::algorithm::bulk_load;
+use crate::algorithm::closest_pair;
+use crate::algorithm::ray_query::RayIntersections;
+use crate::envelope::Envelope;
+use crate::node::{ParentNode, RTreeNode};
+use crate::object::RTreeObject;
+use crate::params::{DefaultParams, InsertionStrategy, RTreeParams};
+use crate::point::Point;
+use crate::structures::aabb::{Ray, AABB};
+use std::marker::PhantomData;
+
+/// An n-dimensional r-tree, accelerating spatial queries over a collection of [`RTreeObject`]s.
+///
+/// `Params` selects the tuning parameters (node size, splitting strategy) used to shape the
+/// tree; see [`DefaultParams`].
+pub struct RTree<T, Params = DefaultParams>
+where
+    T: RTreeObject,
+    Params: RTreeParams,
+{
+    root: ParentNode<T>,
+    size: usize,
+    _params: PhantomData<Params>,
+}
+
+impl<T, Params> RTree<T, Params>
+where
+    T: RTreeObject,
+    Params: RTreeParams,
+{
+    /// Creates a new, empty r-tree.
+    pub fn new() -> Self {
+        RTree {
+            root: ParentNode::new_empty(),
+            size: 0,
+            _params: PhantomData,
+        }
+    }
+
+    /// Bulk-loads an r-tree from `elements`, using `Params::Strategy` to group them into nodes.
+    pub fn bulk_load(elements: Vec<T>) -> Self {
+        let size = elements.len();
+        RTree {
+            root: bulk_load::bulk_load::<T, Params>(elements),
+            size,
+            _params: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements stored in this tree.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Inserts `t` into the tree, splitting the root using `Params::Strategy` once it would
+    /// otherwise hold more than `Params::MAX_SIZE` children.
+    pub fn insert(&mut self, t: T) {
+        self.root.envelope.merge(&t.envelope());
+        self.root.children.push(RTreeNode::Leaf(t));
+        self.size += 1;
+
+        if self.root.children.len() > Params::MAX_SIZE {
+            let right_children = Params::Strategy::split(Params::MIN_SIZE, &mut self.root.children);
+            let left_children = std::mem::take(&mut self.root.children);
+            let left = RTreeNode::Parent(ParentNode::new_from_children(left_children));
+            let right = RTreeNode::Parent(ParentNode::new_from_children(right_children));
+            self.root = ParentNode::new_from_children(vec![left, right]);
+        }
+    }
+
+    /// Returns an iterator over all elements stored in the tree, in unspecified order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            stack: vec![self.root.children.iter()],
+        }
+    }
+
+    /// Returns the closest pair of elements between `self` and `other`, or `None` if either
+    /// tree is empty.
+    ///
+    /// See [`closest_pair`](crate::algorithm::closest_pair::closest_pair) for how the search is
+    /// pruned.
+    pub fn closest_pair<'a, OtherParams>(
+        &'a self,
+        other: &'a RTree<T, OtherParams>,
+    ) -> Option<(&'a T, &'a T)>
+    where
+        OtherParams: RTreeParams,
+    {
+        closest_pair::closest_pair(&self.root, &other.root)
+    }
+}
+
+impl<T, Params> Default for RTree<T, Params>
+where
+    T: RTreeObject,
+    Params: RTreeParams,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over every element of an [`RTree`], in unspecified order.
+pub struct Iter<'a, T>
+where
+    T: RTreeObject,
+{
+    stack: Vec<std::slice::Iter<'a, RTreeNode<T>>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: RTreeObject,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let top = self.stack.last_mut()?;
+            match top.next() {
+                None => {
+                    self.stack.pop();
+                }
+                Some(RTreeNode::Leaf(t)) => return Some(t),
+                Some(RTreeNode::Parent(parent)) => {
+                    self.stack.push(parent.children.iter());
+                }
+            }
+        }
+    }
+}
+
+/// Ray-query methods, available whenever the tree's envelope is an [`AABB`].
+impl<T, Params, P> RTree<T, Params>
+where
+    T: RTreeObject<Envelope = AABB<P>>,
+    Params: RTreeParams,
+    P: Point,
+{
+    /// Returns an iterator over all elements whose envelope `ray` crosses, in increasing order
+    /// of the entry parameter `t` (see [`AABB::intersects_ray`]).
+    pub fn ray_intersections(&self, ray: Ray<P>) -> RayIntersections<'_, T, P> {
+        RayIntersections::new(&self.root, ray)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RTree;
+    use crate::params::SAHParams;
+    use crate::structures::aabb::Ray;
+
+    #[test]
+    fn ray_intersections_are_pruned_and_ordered_by_t() {
+        let mut tree = RTree::<[f64; 2]>::new();
+        for point in [[1.0, 0.0], [3.0, 0.0], [2.0, 0.0], [5.0, 3.0], [-1.0, 0.0]] {
+            tree.insert(point);
+        }
+
+        let ray = Ray::new([0.0, 0.0], [1.0, 0.0]);
+        let hits: Vec<[f64; 2]> = tree.ray_intersections(ray).copied().collect();
+
+        // [5.0, 3.0] and [-1.0, 0.0] do not lie on the ray's path and must be pruned; the rest
+        // must come back ordered by increasing entry `t` (i.e. increasing x).
+        assert_eq!(hits, vec![[1.0, 0.0], [2.0, 0.0], [3.0, 0.0]]);
+    }
+
+    #[test]
+    fn closest_pair_finds_the_nearest_points_across_two_trees() {
+        let mut a = RTree::<[f64; 2]>::new();
+        a.insert([0.0, 0.0]);
+        a.insert([10.0, 10.0]);
+
+        let mut b = RTree::<[f64; 2]>::new();
+        b.insert([0.0, 1.0]);
+        b.insert([20.0, 20.0]);
+
+        let (p, q) = a.closest_pair(&b).unwrap();
+        assert!((*p == [0.0, 0.0] && *q == [0.0, 1.0]) || (*p == [0.0, 1.0] && *q == [0.0, 0.0]));
+    }
+
+    #[test]
+    fn closest_pair_with_an_empty_tree_is_none() {
+        let mut a = RTree::<[f64; 2]>::new();
+        a.insert([0.0, 0.0]);
+        let b = RTree::<[f64; 2]>::new();
+
+        assert!(a.closest_pair(&b).is_none());
+    }
+
+    #[test]
+    fn sah_params_builds_and_queries_a_tree_via_insert_and_bulk_load() {
+        // More than MAX_SIZE elements, so both insert and bulk_load must actually invoke
+        // SAHInsertionStrategy::split rather than fitting in a single leaf node.
+        let points = [
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [0.0, 5.0],
+            [1.0, 5.0],
+            [2.0, 5.0],
+            [5.0, 5.0],
+        ];
+
+        let mut inserted = RTree::<[f64; 2], SAHParams>::new();
+        for point in points {
+            inserted.insert(point);
+        }
+        assert_eq!(inserted.size(), points.len());
+
+        let bulk_loaded = RTree::<[f64; 2], SAHParams>::bulk_load(points.to_vec());
+        assert_eq!(bulk_loaded.size(), points.len());
+
+        let mut expected = points.to_vec();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut inserted_points: Vec<[f64; 2]> = inserted.iter().copied().collect();
+        inserted_points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(inserted_points, expected);
+
+        let mut bulk_loaded_points: Vec<[f64; 2]> = bulk_loaded.iter().copied().collect();
+        bulk_loaded_points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(bulk_loaded_points, expected);
+    }
+}
@@ -101,6 +101,112 @@ where
             self.min_point(point).sub(point).length_2()
         }
     }
+
+    /// Returns the smallest non-negative `t` at which `ray` enters this AABB, or `None` if
+    /// the ray never crosses it.
+    ///
+    /// Uses the slab method: each axis narrows the admissible range of `t` to the interval
+    /// during which the ray lies between that axis' two bounding planes, and the ray hits the
+    /// AABB iff the intersection of all axes' intervals is non-empty and does not lie entirely
+    /// behind the origin. An axis along which the ray is parallel (`direction` component of
+    /// zero) contributes no constraint on `t` as long as the origin already lies within that
+    /// axis' slab; this is handled explicitly rather than relying on floating point infinities,
+    /// so it also holds for integer `Scalar` types.
+    pub fn intersects_ray(&self, ray: &Ray<P>) -> Option<P::Scalar> {
+        let zero = P::Scalar::zero();
+        let mut tmin = P::Scalar::min_value();
+        let mut tmax = P::Scalar::max_value();
+        for i in 0..P::DIMENSIONS {
+            let origin = ray.origin.nth(i);
+            let direction = ray.direction.nth(i);
+            let lower = self.lower.nth(i);
+            let upper = self.upper.nth(i);
+            if direction == zero {
+                if origin < lower || origin > upper {
+                    return None;
+                }
+            } else {
+                let t1 = (lower - origin) / direction;
+                let t2 = (upper - origin) / direction;
+                let (t1, t2) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+                tmin = max_inline(tmin, t1);
+                tmax = if t2 < tmax { t2 } else { tmax };
+            }
+        }
+        if tmax >= tmin && tmax >= zero {
+            Some(max_inline(tmin, zero))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the squared distance between the nearest points of `self` and `other`, or zero
+    /// if the two AABBs intersect.
+    ///
+    /// Computed component-wise: on each axis, the gap between the boxes (zero if they overlap
+    /// on that axis) contributes its square to the sum.
+    pub fn min_distance_2(&self, other: &Self) -> P::Scalar {
+        let zero = P::Scalar::zero();
+        let mut result = zero;
+        for i in 0..P::DIMENSIONS {
+            let gap = max_inline(
+                max_inline(zero, self.lower.nth(i) - other.upper.nth(i)),
+                other.lower.nth(i) - self.upper.nth(i),
+            );
+            result = result + gap * gap;
+        }
+        result
+    }
+
+    /// Returns the squared distance between the farthest corners of `self` and `other`.
+    ///
+    /// Computed component-wise: on each axis, the wider of the two possible corner-to-corner
+    /// gaps contributes its square to the sum.
+    pub fn max_distance_2(&self, other: &Self) -> P::Scalar {
+        let mut result = P::Scalar::zero();
+        for i in 0..P::DIMENSIONS {
+            let gap = max_inline(
+                (self.upper.nth(i) - other.lower.nth(i)).abs(),
+                (other.upper.nth(i) - self.lower.nth(i)).abs(),
+            );
+            result = result + gap * gap;
+        }
+        result
+    }
+}
+
+/// A ray in n-dimensional space, defined by an origin and a direction.
+///
+/// Used together with [`AABB::intersects_ray`] to accelerate ray-casting queries: the slab
+/// method lets a tree prune any subtree whose node AABB the ray does not cross before
+/// descending into it.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub struct Ray<P>
+where
+    P: Point,
+{
+    origin: P,
+    direction: P,
+}
+
+impl<P> Ray<P>
+where
+    P: Point,
+{
+    /// Creates a new ray from an origin point and a direction vector.
+    pub fn new(origin: P, direction: P) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// Returns the ray's origin.
+    pub fn origin(&self) -> P {
+        self.origin
+    }
+
+    /// Returns the ray's direction.
+    pub fn direction(&self) -> P {
+        self.direction
+    }
 }
 
 impl<P> Envelope for AABB<P>
@@ -177,6 +283,14 @@ where
         result
     }
 
+    fn min_distance_2(&self, other: &Self) -> P::Scalar {
+        self.min_distance_2(other)
+    }
+
+    fn max_distance_2(&self, other: &Self) -> P::Scalar {
+        self.max_distance_2(other)
+    }
+
     fn center(&self) -> Self::Point {
         let one = <Self::Point as Point>::Scalar::one();
         let two = one + one;
@@ -208,4 +322,74 @@ where
                 .unwrap()
         });
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Ray, AABB};
+
+    #[test]
+    fn intersects_ray_starting_inside_returns_zero() {
+        let aabb = AABB::from_corners([0.0, 0.0], [2.0, 2.0]);
+        let ray = Ray::new([1.0, 1.0], [1.0, 0.0]);
+        assert_eq!(aabb.intersects_ray(&ray), Some(0.0));
+    }
+
+    #[test]
+    fn intersects_ray_entirely_behind_origin_misses() {
+        let aabb = AABB::from_corners([0.0, 0.0], [2.0, 2.0]);
+        let ray = Ray::new([5.0, 1.0], [1.0, 0.0]);
+        assert_eq!(aabb.intersects_ray(&ray), None);
+    }
+
+    #[test]
+    fn intersects_ray_parallel_to_slab_inside_hits() {
+        // The ray never moves along the y axis; it only hits the box if its y coordinate
+        // already lies within the box's y slab.
+        let aabb = AABB::from_corners([0.0, 0.0], [2.0, 2.0]);
+        let ray = Ray::new([-1.0, 1.0], [1.0, 0.0]);
+        assert_eq!(aabb.intersects_ray(&ray), Some(1.0));
+    }
+
+    #[test]
+    fn intersects_ray_parallel_to_slab_outside_misses() {
+        let aabb = AABB::from_corners([0.0, 0.0], [2.0, 2.0]);
+        let ray = Ray::new([-1.0, 5.0], [1.0, 0.0]);
+        assert_eq!(aabb.intersects_ray(&ray), None);
+    }
+
+    #[test]
+    fn intersects_ray_zero_direction_integer_path() {
+        // With an integer scalar, dividing by a zero direction can't yield an IEEE infinity, so
+        // the zero-direction case must be handled explicitly rather than falling out of the
+        // division.
+        let aabb = AABB::from_corners([0i32, 0], [2, 2]);
+        let inside = Ray::new([1, 1], [0, 1]);
+        assert_eq!(aabb.intersects_ray(&inside), Some(0));
+        let outside = Ray::new([5, 1], [0, 1]);
+        assert_eq!(aabb.intersects_ray(&outside), None);
+    }
+
+    #[test]
+    fn min_distance_2_is_zero_for_overlapping_boxes() {
+        let a = AABB::from_corners([0.0, 0.0], [2.0, 2.0]);
+        let b = AABB::from_corners([1.0, 1.0], [3.0, 3.0]);
+        assert_eq!(a.min_distance_2(&b), 0.0);
+    }
+
+    #[test]
+    fn min_distance_2_sums_the_gap_on_every_separating_axis() {
+        let a = AABB::from_corners([0.0, 0.0], [1.0, 1.0]);
+        let b = AABB::from_corners([4.0, 6.0], [5.0, 7.0]);
+        // Gap of 3 on x (4 - 1) and 5 on y (6 - 1): 3^2 + 5^2 = 34.
+        assert_eq!(a.min_distance_2(&b), 34.0);
+    }
+
+    #[test]
+    fn max_distance_2_uses_the_farthest_corners_across_the_origin() {
+        let a = AABB::from_corners([-1.0, -1.0], [0.0, 0.0]);
+        let b = AABB::from_corners([1.0, 2.0], [2.0, 3.0]);
+        // Farthest corners are (-1, -1) and (2, 3): 3^2 + 4^2 = 25.
+        assert_eq!(a.max_distance_2(&b), 25.0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,34 @@
+use crate::envelope::Envelope;
+use crate::object::RTreeObject;
+use crate::point::Point;
+use std::cmp::Ordering;
+
+/// The scalar type of an [`RTreeObject`]'s envelope's point, i.e. the type its distances and
+/// areas are measured in. Shared by the closest-pair search and SAH splitting so they can't
+/// drift apart on how they spell this out.
+pub(crate) type Scalar<T> = <<<T as RTreeObject>::Envelope as Envelope>::Point as Point>::Scalar;
+
+/// Wraps a `Scalar` so it orders as a min-heap key via `BinaryHeap` (which is a max-heap),
+/// assuming no `NaN` values are ever pushed. Shared by the best-first tree traversals (ray
+/// queries, closest-pair search).
+pub(crate) struct MinScalar<S>(pub S);
+
+impl<S: PartialEq> PartialEq for MinScalar<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<S: PartialEq> Eq for MinScalar<S> {}
+impl<S: PartialOrd> PartialOrd for MinScalar<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<S: PartialOrd> Ord for MinScalar<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .partial_cmp(&self.0)
+            .expect("encountered an incomparable (NaN) scalar during a best-first traversal")
+    }
+}